@@ -0,0 +1,219 @@
+//! `#[derive(Eh)]` for the [`eh`](https://docs.rs/eh) crate.
+//!
+//! For a struct, the derived `eh()` returns `true` iff any field is truthy.
+//! Use `#[eh(all)]` to require every field to be truthy instead, or
+//! `#[eh(field)]` on a single field (or `#[eh(field = "name")]` on the
+//! struct) to let that one field decide:
+//!
+//! ```
+//! use eh::Eh;
+//!
+//! #[derive(Eh)]
+//! struct AnyTruthy {
+//!     a: i32,
+//!     b: Option<i32>,
+//! }
+//!
+//! #[derive(Eh)]
+//! #[eh(all)]
+//! struct AllTruthy {
+//!     a: i32,
+//!     b: Option<i32>,
+//! }
+//!
+//! #[derive(Eh)]
+//! struct OneField {
+//!     #[eh(field)]
+//!     count: i32,
+//!     label: &'static str,
+//! }
+//!
+//! assert!(AnyTruthy { a: 0, b: Some(1) }.eh());
+//! assert!(!AllTruthy { a: 0, b: Some(1) }.eh());
+//! assert!(!OneField { count: 0, label: "non-empty" }.eh());
+//! ```
+//!
+//! For an enum, annotate the variants that are truthy or falsy; every
+//! variant must be annotated, since there's no default that makes sense for
+//! an arbitrary state machine:
+//!
+//! ```
+//! use eh::Eh;
+//!
+//! #[derive(Eh)]
+//! enum State {
+//!     #[eh(falsy)]
+//!     Empty,
+//!     #[eh(truthy)]
+//!     Loaded(Vec<u8>),
+//! }
+//!
+//! assert!(!State::Empty.eh());
+//! assert!(State::Loaded(vec![1]).eh());
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident};
+
+/// How a struct's fields combine into a single `eh()` result.
+enum StructMode {
+    Any,
+    All,
+    Field(syn::Member),
+}
+
+#[proc_macro_derive(Eh, attributes(eh))]
+pub fn derive_eh(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => struct_body(&input.attrs, data),
+        Data::Enum(data) => enum_body(name, data),
+        Data::Union(_) => {
+            return syn::Error::new_spanned(&input, "`derive(Eh)` does not support unions")
+                .to_compile_error()
+                .into();
+        }
+    };
+    let body = match body {
+        Ok(body) => body,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let expanded = quote! {
+        impl #impl_generics ::eh::Eh for #name #ty_generics #where_clause {
+            #[inline]
+            fn eh(&self) -> bool {
+                #body
+            }
+        }
+    };
+    expanded.into()
+}
+
+fn struct_mode(attrs: &[syn::Attribute], fields: &Fields) -> syn::Result<StructMode> {
+    for attr in attrs {
+        if !attr.path().is_ident("eh") {
+            continue;
+        }
+        let mut mode = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("all") {
+                mode = Some(StructMode::All);
+            } else if meta.path.is_ident("any") {
+                mode = Some(StructMode::Any);
+            } else if meta.path.is_ident("field") {
+                let value = meta.value()?;
+                let lit: syn::LitStr = value.parse()?;
+                // A field name, or a tuple field index such as `"0"`.
+                mode = Some(StructMode::Field(lit.parse()?));
+            } else {
+                return Err(meta.error("expected `all`, `any`, or `field = \"...\"`"));
+            }
+            Ok(())
+        })?;
+        if let Some(mode) = mode {
+            return Ok(mode);
+        }
+    }
+
+    for field in fields {
+        for attr in &field.attrs {
+            if !attr.path().is_ident("eh") {
+                continue;
+            }
+            let mut is_decider = false;
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("field") {
+                    is_decider = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `field`"))
+                }
+            })?;
+            if is_decider {
+                let ident = field
+                    .ident
+                    .clone()
+                    .ok_or_else(|| syn::Error::new_spanned(field, "tuple fields need `#[eh(field = \"name\")]` on the struct instead"))?;
+                return Ok(StructMode::Field(syn::Member::Named(ident)));
+            }
+        }
+    }
+
+    Ok(StructMode::Any)
+}
+
+fn struct_body(attrs: &[syn::Attribute], data: &syn::DataStruct) -> syn::Result<TokenStream2> {
+    let mode = struct_mode(attrs, &data.fields)?;
+
+    match mode {
+        StructMode::Field(ident) => Ok(quote! { ::eh::Eh::eh(&self.#ident) }),
+        StructMode::Any => {
+            let checks = field_accessors(&data.fields).map(|f| quote! { ::eh::Eh::eh(&#f) });
+            Ok(quote! { false #(|| #checks)* })
+        }
+        StructMode::All => {
+            let checks = field_accessors(&data.fields).map(|f| quote! { ::eh::Eh::eh(&#f) });
+            Ok(quote! { true #(&& #checks)* })
+        }
+    }
+}
+
+fn field_accessors(fields: &Fields) -> impl Iterator<Item = TokenStream2> + '_ {
+    fields.iter().enumerate().map(|(i, field)| match &field.ident {
+        Some(ident) => quote! { self.#ident },
+        None => {
+            let index = syn::Index::from(i);
+            quote! { self.#index }
+        }
+    })
+}
+
+fn enum_body(name: &Ident, data: &syn::DataEnum) -> syn::Result<TokenStream2> {
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        let mut truthy = None;
+        for attr in &variant.attrs {
+            if !attr.path().is_ident("eh") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("truthy") {
+                    truthy = Some(true);
+                } else if meta.path.is_ident("falsy") {
+                    truthy = Some(false);
+                } else {
+                    return Err(meta.error("expected `truthy` or `falsy`"));
+                }
+                Ok(())
+            })?;
+        }
+        let truthy = truthy.ok_or_else(|| {
+            syn::Error::new_spanned(
+                variant,
+                "every variant needs `#[eh(truthy)]` or `#[eh(falsy)]`",
+            )
+        })?;
+
+        let ident = &variant.ident;
+        let pattern = match &variant.fields {
+            Fields::Named(_) => quote! { #name::#ident { .. } },
+            Fields::Unnamed(_) => quote! { #name::#ident(..) },
+            Fields::Unit => quote! { #name::#ident },
+        };
+        arms.push(quote! { #pattern => #truthy });
+    }
+
+    Ok(quote! {
+        match self {
+            #(#arms,)*
+        }
+    })
+}
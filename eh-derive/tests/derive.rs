@@ -0,0 +1,56 @@
+use eh::Eh;
+
+#[derive(Eh)]
+struct AnyTruthy {
+    a: i32,
+    b: Option<i32>,
+}
+
+#[derive(Eh)]
+#[eh(all)]
+struct AllTruthy {
+    a: i32,
+    b: Option<i32>,
+}
+
+#[derive(Eh)]
+struct OneField {
+    #[eh(field)]
+    count: i32,
+    #[allow(dead_code)]
+    label: &'static str,
+}
+
+#[derive(Eh)]
+enum State {
+    #[eh(falsy)]
+    Empty,
+    #[eh(truthy)]
+    Loaded(#[allow(dead_code)] u8),
+}
+
+#[test]
+fn any_field_truthy() {
+    assert!(AnyTruthy { a: 1, b: None }.eh());
+    assert!(AnyTruthy { a: 0, b: Some(1) }.eh());
+    assert!(!AnyTruthy { a: 0, b: None }.eh());
+}
+
+#[test]
+fn all_fields_truthy() {
+    assert!(!AllTruthy { a: 1, b: None }.eh());
+    assert!(AllTruthy { a: 1, b: Some(1) }.eh());
+    assert!(!AllTruthy { a: 0, b: Some(1) }.eh());
+}
+
+#[test]
+fn single_field_decides() {
+    assert!(OneField { count: 1, label: "" }.eh());
+    assert!(!OneField { count: 0, label: "non-empty" }.eh());
+}
+
+#[test]
+fn enum_variants() {
+    assert!(!State::Empty.eh());
+    assert!(State::Loaded(0).eh());
+}
@@ -100,6 +100,10 @@
 //! - Many languages convert empty strings to `false` and non-empty strings to `true`,
 //!   but `"0"` is also `false` in Perl and PHP.
 //!
+//! String truthiness isn't universal enough for `Eh` itself, but the
+//! `str` feature adds an opt-in [`StrEh`] trait that takes an explicit
+//! [`Dialect`] instead.
+//!
 //! ## About
 //!
 //! The name is a play on the [Canadian "eh"][eh], turning a declarative
@@ -109,9 +113,87 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Derive [`Eh`] for a struct or enum.
+///
+/// See the [`eh_derive`](https://docs.rs/eh-derive) crate docs for the
+/// `#[eh(...)]` attributes this supports.
+#[cfg(feature = "derive")]
+pub use eh_derive::Eh;
+
 /// Convert values to `bool`, kind of like C, eh?
 pub trait Eh {
     fn eh(&self) -> bool;
+
+    /// Short-circuiting logical AND with another truthy value, like C's `&&`.
+    ///
+    /// The closure `other` is only called if `self` is already truthy, so it
+    /// may perform side effects or computations that should be skipped
+    /// otherwise.
+    ///
+    /// ```
+    /// use eh::Eh;
+    /// assert!(1.eh_and(|| Some(2)));
+    /// assert!(!0.eh_and(|| Some(2)));
+    /// assert!(!1.eh_and(|| None::<i32>));
+    /// ```
+    #[inline]
+    fn eh_and<T: Eh, F: FnOnce() -> T>(&self, other: F) -> bool {
+        self.eh() && other().eh()
+    }
+
+    /// Short-circuiting logical OR with another truthy value, like C's `||`.
+    ///
+    /// The closure `other` is only called if `self` is already falsy, so it
+    /// may perform side effects or computations that should be skipped
+    /// otherwise.
+    ///
+    /// ```
+    /// use eh::Eh;
+    /// assert!(1.eh_or(|| None::<i32>));
+    /// assert!(0.eh_or(|| Some(2)));
+    /// assert!(!0.eh_or(|| None::<i32>));
+    /// ```
+    #[inline]
+    fn eh_or<T: Eh, F: FnOnce() -> T>(&self, other: F) -> bool {
+        self.eh() || other().eh()
+    }
+
+    /// Logical XOR with another truthy value: `true` iff exactly one side is
+    /// truthy.
+    ///
+    /// Unlike [`eh_and`] and [`eh_or`], both sides must always be evaluated
+    /// to decide the result, so `other` is taken by reference rather than as
+    /// a closure.
+    ///
+    /// ```
+    /// use eh::Eh;
+    /// assert!(1.eh_xor(&0));
+    /// assert!(0.eh_xor(&1));
+    /// assert!(!1.eh_xor(&1));
+    /// assert!(!0.eh_xor(&0));
+    /// ```
+    ///
+    /// [`eh_and`]: Eh::eh_and
+    /// [`eh_or`]: Eh::eh_or
+    #[inline]
+    fn eh_xor<T: Eh>(&self, other: &T) -> bool {
+        self.eh() ^ other.eh()
+    }
+
+    /// Logical NOT, like C's `!`.
+    ///
+    /// ```
+    /// use eh::Eh;
+    /// assert!(0.eh_not());
+    /// assert!(!1.eh_not());
+    /// ```
+    #[inline]
+    fn eh_not(&self) -> bool {
+        !self.eh()
+    }
 }
 
 impl Eh for bool {
@@ -180,3 +262,283 @@ impl<T, E> Eh for Result<T, E> {
         self.is_ok()
     }
 }
+
+/// Produce a type's canonical truthy or falsy value from a `bool`, the
+/// reverse of [`Eh`].
+///
+/// C semantics say that casting a `bool` to an integer gives `1` for `true`
+/// and `0` for `false`; `un_eh` generalizes that to every type [`Eh`]
+/// covers. For the numeric impls, round-tripping through [`Eh::eh`] recovers
+/// the original `bool`:
+///
+/// ```
+/// use eh::{Eh, UnEh};
+/// assert_eq!(i32::un_eh(true), 1);
+/// assert_eq!(i32::un_eh(false), 0);
+/// assert!(i32::un_eh(true).eh());
+/// assert!(!i32::un_eh(false).eh());
+/// ```
+///
+/// Pointers round-trip the same way, using a well-aligned dangling sentinel
+/// for `true` rather than dereferencing anything:
+///
+/// ```
+/// use eh::{Eh, UnEh};
+/// assert!(<*const i32>::un_eh(true).eh());
+/// assert!(!<*const i32>::un_eh(false).eh());
+/// ```
+pub trait UnEh: Sized {
+    fn un_eh(b: bool) -> Self;
+}
+
+impl UnEh for bool {
+    #[inline]
+    fn un_eh(b: bool) -> Self {
+        b
+    }
+}
+
+macro_rules! int_un_eh {
+    ($($T:ty),*) => {$(
+        impl UnEh for $T {
+            #[inline]
+            fn un_eh(b: bool) -> Self {
+                b as $T
+            }
+        }
+    )*}
+}
+int_un_eh! { i8, i16, i32, i64, i128, isize }
+int_un_eh! { u8, u16, u32, u64, u128, usize }
+
+macro_rules! float_un_eh {
+    ($($T:ty),*) => {$(
+        impl UnEh for $T {
+            #[inline]
+            fn un_eh(b: bool) -> Self {
+                if b { 1.0 } else { 0.0 }
+            }
+        }
+    )*}
+}
+float_un_eh! { f32, f64 }
+
+// There's no single "canonical" non-null pointer, so `true` maps to the same
+// dangling-but-well-aligned sentinel as `NonNull::dangling`, matching its
+// documented use as a non-null placeholder that's never dereferenced.
+impl<T> UnEh for *const T {
+    #[inline]
+    fn un_eh(b: bool) -> Self {
+        if b {
+            core::ptr::NonNull::dangling().as_ptr()
+        } else {
+            core::ptr::null()
+        }
+    }
+}
+
+impl<T> UnEh for *mut T {
+    #[inline]
+    fn un_eh(b: bool) -> Self {
+        if b {
+            core::ptr::NonNull::dangling().as_ptr()
+        } else {
+            core::ptr::null_mut()
+        }
+    }
+}
+
+impl<T: UnEh> UnEh for core::num::Wrapping<T> {
+    #[inline]
+    fn un_eh(b: bool) -> Self {
+        core::num::Wrapping(T::un_eh(b))
+    }
+}
+
+/// Produce a caller-supplied `Some`/`None` from a `bool`, the [`Option`]
+/// counterpart of [`UnEh`].
+///
+/// `Option<T>` can't implement `UnEh` directly since a `Some` value needs a
+/// `T` to hold, so the value is supplied by the caller instead:
+///
+/// ```
+/// use eh::OptionUnEh;
+/// assert_eq!(Option::un_eh(true, 5), Some(5));
+/// assert_eq!(Option::un_eh(false, 5), None);
+/// ```
+pub trait OptionUnEh<T>: Sized {
+    fn un_eh(b: bool, value: T) -> Self;
+}
+
+impl<T> OptionUnEh<T> for Option<T> {
+    #[inline]
+    fn un_eh(b: bool, value: T) -> Self {
+        if b {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+/// Produce a caller-supplied `Ok`/`Err` from a `bool`, the [`Result`]
+/// counterpart of [`UnEh`].
+///
+/// `Result<T, E>` can't implement `UnEh` directly since `Ok` and `Err` each
+/// need a value to hold, so both are supplied by the caller:
+///
+/// ```
+/// use eh::ResultUnEh;
+/// assert_eq!(Result::<i32, &str>::un_eh(true, 5, "nope"), Ok(5));
+/// assert_eq!(Result::<i32, &str>::un_eh(false, 5, "nope"), Err("nope"));
+/// ```
+pub trait ResultUnEh<T, E>: Sized {
+    fn un_eh(b: bool, ok: T, err: E) -> Self;
+}
+
+impl<T, E> ResultUnEh<T, E> for Result<T, E> {
+    #[inline]
+    fn un_eh(b: bool, ok: T, err: E) -> Self {
+        if b {
+            Ok(ok)
+        } else {
+            Err(err)
+        }
+    }
+}
+
+/// A language's rule for converting strings to `bool`, used by [`StrEh`].
+///
+/// There's no single universal rule for string truthiness -- see the crate
+/// [Exclusions](index.html#exclusions) section -- so the caller must pick a
+/// dialect explicitly.
+#[cfg(feature = "str")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    /// Any non-empty string is truthy, matching C and C++.
+    C,
+    /// Like [`C`](Dialect::C), but `"0"` is also falsy, matching Perl and PHP.
+    PerlPhp,
+    /// Only the empty string is falsy, matching JavaScript's string rule.
+    JavaScript,
+    /// Only the empty string is falsy, matching Python's string rule.
+    Python,
+}
+
+/// String truthiness, gated behind an explicit [`Dialect`] since it isn't
+/// universal across languages -- see the crate
+/// [Exclusions](index.html#exclusions) section.
+///
+/// ```
+/// use eh::{Dialect, StrEh};
+/// assert!("0".str_eh(Dialect::C));
+/// assert!(!"0".str_eh(Dialect::PerlPhp));
+/// assert!("0".str_eh(Dialect::JavaScript));
+/// assert!(!"".str_eh(Dialect::Python));
+/// ```
+#[cfg(feature = "str")]
+pub trait StrEh {
+    fn str_eh(&self, dialect: Dialect) -> bool;
+}
+
+#[cfg(feature = "str")]
+impl StrEh for str {
+    #[inline]
+    fn str_eh(&self, dialect: Dialect) -> bool {
+        match dialect {
+            Dialect::PerlPhp => !self.is_empty() && self != "0",
+            Dialect::C | Dialect::JavaScript | Dialect::Python => !self.is_empty(),
+        }
+    }
+}
+
+#[cfg(all(feature = "str", feature = "alloc"))]
+impl StrEh for alloc::string::String {
+    #[inline]
+    fn str_eh(&self, dialect: Dialect) -> bool {
+        self.as_str().str_eh(dialect)
+    }
+}
+
+/// Decide truthiness and re-encode the original magnitude into `T` with a
+/// wrapping `as` cast, all in one call.
+///
+/// This mirrors Rust's documented `as` cast rules for integer-to-integer
+/// conversions: the value is truncated, zero-extended or sign-extended to
+/// the bit width `N` of `T`, which is the same as adding or subtracting
+/// `2^N` until it fits (for an unsigned `T`, `2^N` is `T::MAX + 1`).
+///
+/// ```
+/// use eh::EhWrapping;
+/// let wrapped: (bool, u8) = 1000.eh_wrapping();
+/// assert_eq!(wrapped, (true, 232));
+/// let wrapped: (bool, u8) = (-1i8).eh_wrapping();
+/// assert_eq!(wrapped, (true, 255));
+/// let wrapped: (bool, u8) = 0.eh_wrapping();
+/// assert_eq!(wrapped, (false, 0));
+/// ```
+pub trait EhWrapping<T> {
+    fn eh_wrapping(&self) -> (bool, T);
+}
+
+macro_rules! eh_wrapping_int {
+    (@impl [$($Dst:ty),*] for $Src:ty) => {
+        $(
+            impl EhWrapping<$Dst> for $Src {
+                #[inline]
+                fn eh_wrapping(&self) -> (bool, $Dst) {
+                    (self.eh(), *self as $Dst)
+                }
+            }
+        )*
+    };
+    ($dst:tt for $($Src:ty),*) => {
+        $( eh_wrapping_int!(@impl $dst for $Src); )*
+    };
+}
+eh_wrapping_int!(
+    [i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize]
+    for i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Decide truthiness and re-encode the original magnitude into `T` with a
+/// saturating `as` cast, all in one call.
+///
+/// This mirrors Rust's documented `as` cast rules for float-to-integer
+/// conversions: values above `T::MAX` saturate to `T::MAX`, values below
+/// `T::MIN` saturate to `T::MIN`, and NaN becomes `0`.
+///
+/// ```
+/// use eh::EhSaturating;
+/// let saturated: (bool, i32) = 1e20_f64.eh_saturating();
+/// assert_eq!(saturated, (true, i32::MAX));
+/// let saturated: (bool, i32) = (-1e20_f64).eh_saturating();
+/// assert_eq!(saturated, (true, i32::MIN));
+/// let saturated: (bool, i32) = f64::NAN.eh_saturating();
+/// assert_eq!(saturated, (true, 0));
+/// let saturated: (bool, i32) = 0.0_f64.eh_saturating();
+/// assert_eq!(saturated, (false, 0));
+/// ```
+pub trait EhSaturating<T> {
+    fn eh_saturating(&self) -> (bool, T);
+}
+
+macro_rules! eh_saturating_float {
+    (@impl [$($Dst:ty),*] for $Src:ty) => {
+        $(
+            impl EhSaturating<$Dst> for $Src {
+                #[inline]
+                fn eh_saturating(&self) -> (bool, $Dst) {
+                    (self.eh(), *self as $Dst)
+                }
+            }
+        )*
+    };
+    ($dst:tt for $($Src:ty),*) => {
+        $( eh_saturating_float!(@impl $dst for $Src); )*
+    };
+}
+eh_saturating_float!(
+    [i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize]
+    for f32, f64
+);